@@ -0,0 +1,106 @@
+//! Portable full-text search predicates.
+//!
+//! The [`FullTextMatchDsl`](trait.FullTextMatchDsl.html) extension method
+//! builds a [`FullTextMatch`](struct.FullTextMatch.html) node whose rendering
+//! depends on the backend: SQLite emits the FTS `MATCH` operator, while
+//! Postgres matches a `tsvector` column against `to_tsquery(?)` with `@@`.
+
+use backend::Backend;
+use expression::{AppearsOnTable, AsExpression, Expression, NonAggregate, SelectableExpression};
+use query_builder::{QueryBuilder, QueryFragment, BuildQueryResult, AstPass};
+use result::QueryResult;
+use types::{Bool, Text};
+
+/// A full-text match predicate, e.g. `body MATCH ?` or `body @@ to_tsquery(?)`.
+#[derive(Debug, Clone, Copy)]
+pub struct FullTextMatch<Left, Right> {
+    left: Left,
+    right: Right,
+}
+
+impl<Left, Right> FullTextMatch<Left, Right> {
+    fn new(left: Left, right: Right) -> Self {
+        FullTextMatch { left: left, right: right }
+    }
+}
+
+impl<Left, Right> Expression for FullTextMatch<Left, Right> where
+    Left: Expression,
+    Right: Expression<SqlType = Text>,
+{
+    type SqlType = Bool;
+}
+
+impl<Left, Right, QS> SelectableExpression<QS> for FullTextMatch<Left, Right> where
+    Left: SelectableExpression<QS>,
+    FullTextMatch<Left, Right>: AppearsOnTable<QS>,
+{
+}
+
+impl<Left, Right, QS> AppearsOnTable<QS> for FullTextMatch<Left, Right> where
+    Left: AppearsOnTable<QS>,
+    Right: AppearsOnTable<QS>,
+    FullTextMatch<Left, Right>: Expression,
+{
+}
+
+impl<Left, Right> NonAggregate for FullTextMatch<Left, Right> where
+    Left: NonAggregate,
+    Right: NonAggregate,
+    FullTextMatch<Left, Right>: Expression,
+{
+}
+
+impl_query_id!(FullTextMatch<Left, Right>);
+
+#[cfg(feature = "sqlite")]
+impl<Left, Right> QueryFragment<::sqlite::Sqlite> for FullTextMatch<Left, Right> where
+    Left: QueryFragment<::sqlite::Sqlite>,
+    Right: QueryFragment<::sqlite::Sqlite>,
+{
+    fn to_sql(&self, out: &mut <::sqlite::Sqlite as Backend>::QueryBuilder) -> BuildQueryResult {
+        try!(self.left.to_sql(out));
+        out.push_sql(" MATCH ");
+        self.right.to_sql(out)
+    }
+
+    fn walk_ast(&self, pass: &mut AstPass<::sqlite::Sqlite>) -> QueryResult<()> {
+        self.left.walk_ast(pass)?;
+        self.right.walk_ast(pass)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<Left, Right> QueryFragment<::pg::Pg> for FullTextMatch<Left, Right> where
+    Left: QueryFragment<::pg::Pg>,
+    Right: QueryFragment<::pg::Pg>,
+{
+    fn to_sql(&self, out: &mut <::pg::Pg as Backend>::QueryBuilder) -> BuildQueryResult {
+        try!(self.left.to_sql(out));
+        out.push_sql(" @@ to_tsquery(");
+        try!(self.right.to_sql(out));
+        out.push_sql(")");
+        Ok(())
+    }
+
+    fn walk_ast(&self, pass: &mut AstPass<::pg::Pg>) -> QueryResult<()> {
+        self.left.walk_ast(pass)?;
+        self.right.walk_ast(pass)?;
+        Ok(())
+    }
+}
+
+/// Adds the `full_text_match` method to expressions, allowing portable
+/// full-text `.filter(...)` predicates across SQLite FTS and Postgres indexes.
+pub trait FullTextMatchDsl: Expression + Sized {
+    /// Builds a full-text match against `query`, binding the query text as a
+    /// parameter.
+    fn full_text_match<T>(self, query: T) -> FullTextMatch<Self, T::Expression> where
+        T: AsExpression<Text>,
+    {
+        FullTextMatch::new(self, query.as_expression())
+    }
+}
+
+impl<T: Expression> FullTextMatchDsl for T {}
@@ -0,0 +1,177 @@
+//! Set-operation combinators joining two queries with `UNION`, `INTERSECT`
+//! or `EXCEPT`.
+//!
+//! A [`CombinationClause`](struct.CombinationClause.html) is itself a
+//! [`Query`](../trait.Query.html) whose `SqlType` is taken from its left-hand
+//! operand. Any `ORDER BY`/`LIMIT` applied to it wraps the whole compound and
+//! is rendered *outside* the parenthesized operands, so it never leaks into
+//! either side.
+
+use backend::Backend;
+use expression::{Expression, IntoSql};
+use expression::helper_types::AsExprOf;
+use query_builder::{Query, QueryBuilder, QueryFragment, BuildQueryResult, AstPass};
+use query_dsl::{LimitDsl, OrderDsl};
+use result::QueryResult;
+use types::BigInt;
+use super::limit_clause::{LimitClause, NoLimitClause};
+use super::order_clause::{NoOrderClause, OrderClause};
+
+/// A query combining two operands with a set operator.
+///
+/// The `Order`/`Limit` type parameters hold any trailing clauses applied to the
+/// compound as a whole; they default to the no-op markers and are rendered
+/// after the closing parenthesis of the right-hand operand.
+#[derive(Debug, Clone, Copy)]
+#[must_use="Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct CombinationClause<Combinator, Lhs, Rhs, Order = NoOrderClause, Limit = NoLimitClause> {
+    combinator: Combinator,
+    lhs: Lhs,
+    rhs: Rhs,
+    order: Order,
+    limit: Limit,
+}
+
+impl<Combinator, Lhs, Rhs> CombinationClause<Combinator, Lhs, Rhs> {
+    fn new(combinator: Combinator, lhs: Lhs, rhs: Rhs) -> Self {
+        CombinationClause {
+            combinator: combinator,
+            lhs: lhs,
+            rhs: rhs,
+            order: NoOrderClause,
+            limit: NoLimitClause,
+        }
+    }
+}
+
+impl<Combinator, Lhs, Rhs, Order, Limit> Query
+    for CombinationClause<Combinator, Lhs, Rhs, Order, Limit> where
+        Lhs: Query,
+        Rhs: Query<SqlType = Lhs::SqlType>,
+{
+    type SqlType = Lhs::SqlType;
+}
+
+impl<Combinator, Lhs, Rhs, Order, Limit, DB> QueryFragment<DB>
+    for CombinationClause<Combinator, Lhs, Rhs, Order, Limit> where
+        DB: Backend,
+        Combinator: QueryFragment<DB>,
+        Lhs: QueryFragment<DB>,
+        Rhs: QueryFragment<DB>,
+        Order: QueryFragment<DB>,
+        Limit: QueryFragment<DB>,
+{
+    fn to_sql(&self, out: &mut DB::QueryBuilder) -> BuildQueryResult {
+        out.push_sql("(");
+        try!(self.lhs.to_sql(out));
+        out.push_sql(") ");
+        try!(self.combinator.to_sql(out));
+        out.push_sql(" (");
+        try!(self.rhs.to_sql(out));
+        out.push_sql(")");
+        try!(self.order.to_sql(out));
+        try!(self.limit.to_sql(out));
+        Ok(())
+    }
+
+    fn walk_ast(&self, pass: &mut AstPass<DB>) -> QueryResult<()> {
+        self.lhs.walk_ast(pass)?;
+        self.rhs.walk_ast(pass)?;
+        self.order.walk_ast(pass)?;
+        self.limit.walk_ast(pass)?;
+        Ok(())
+    }
+}
+
+impl_query_id!(CombinationClause<Combinator, Lhs, Rhs, Order, Limit>);
+
+impl<Combinator, Lhs, Rhs, Order, Limit, Expr> OrderDsl<Expr>
+    for CombinationClause<Combinator, Lhs, Rhs, Order, Limit> where
+        Expr: Expression,
+{
+    type Output = CombinationClause<Combinator, Lhs, Rhs, OrderClause<Expr>, Limit>;
+
+    fn order(self, expr: Expr) -> Self::Output {
+        CombinationClause {
+            combinator: self.combinator,
+            lhs: self.lhs,
+            rhs: self.rhs,
+            order: OrderClause(expr),
+            limit: self.limit,
+        }
+    }
+}
+
+impl<Combinator, Lhs, Rhs, Order, Limit> LimitDsl
+    for CombinationClause<Combinator, Lhs, Rhs, Order, Limit>
+{
+    type Output = CombinationClause<Combinator, Lhs, Rhs, Order, LimitClause<AsExprOf<i64, BigInt>>>;
+
+    fn limit(self, limit: i64) -> Self::Output {
+        CombinationClause {
+            combinator: self.combinator,
+            lhs: self.lhs,
+            rhs: self.rhs,
+            order: self.order,
+            limit: LimitClause(limit.into_sql::<BigInt>()),
+        }
+    }
+}
+
+macro_rules! combinator {
+    ($name:ident, $keyword:expr) => {
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+
+        impl<DB: Backend> QueryFragment<DB> for $name {
+            fn to_sql(&self, out: &mut DB::QueryBuilder) -> BuildQueryResult {
+                out.push_sql($keyword);
+                Ok(())
+            }
+
+            fn walk_ast(&self, _: &mut AstPass<DB>) -> QueryResult<()> {
+                Ok(())
+            }
+        }
+
+        impl_query_id!($name);
+    }
+}
+
+combinator!(Union, "UNION");
+combinator!(UnionAll, "UNION ALL");
+combinator!(Intersect, "INTERSECT");
+combinator!(Except, "EXCEPT");
+
+/// Adds the set-operation combinators to every [`Query`](../trait.Query.html).
+pub trait CombineDsl: Query + Sized {
+    /// `(self) UNION (rhs)`, discarding duplicate rows.
+    fn union<Rhs>(self, rhs: Rhs) -> CombinationClause<Union, Self, Rhs> where
+        Rhs: Query<SqlType = Self::SqlType>,
+    {
+        CombinationClause::new(Union, self, rhs)
+    }
+
+    /// `(self) UNION ALL (rhs)`, keeping duplicate rows.
+    fn union_all<Rhs>(self, rhs: Rhs) -> CombinationClause<UnionAll, Self, Rhs> where
+        Rhs: Query<SqlType = Self::SqlType>,
+    {
+        CombinationClause::new(UnionAll, self, rhs)
+    }
+
+    /// `(self) INTERSECT (rhs)`.
+    fn intersect<Rhs>(self, rhs: Rhs) -> CombinationClause<Intersect, Self, Rhs> where
+        Rhs: Query<SqlType = Self::SqlType>,
+    {
+        CombinationClause::new(Intersect, self, rhs)
+    }
+
+    /// `(self) EXCEPT (rhs)`.
+    fn except<Rhs>(self, rhs: Rhs) -> CombinationClause<Except, Self, Rhs> where
+        Rhs: Query<SqlType = Self::SqlType>,
+    {
+        CombinationClause::new(Except, self, rhs)
+    }
+}
+
+impl<T: Query> CombineDsl for T {}
@@ -0,0 +1,39 @@
+use backend::Backend;
+use expression::Expression;
+use result::QueryResult;
+use types::Bool;
+use super::{QueryBuilder, QueryFragment, BuildQueryResult, AstPass};
+
+#[derive(Debug, Clone, Copy)]
+pub struct NoHavingClause;
+
+impl<DB: Backend> QueryFragment<DB> for NoHavingClause {
+    fn to_sql(&self, _: &mut DB::QueryBuilder) -> BuildQueryResult {
+        Ok(())
+    }
+
+    fn walk_ast(&self, _: &mut AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl_query_id!(NoHavingClause);
+
+#[derive(Debug, Clone, Copy)]
+pub struct HavingClause<Expr>(pub Expr);
+
+impl<DB, Expr> QueryFragment<DB> for HavingClause<Expr> where
+    DB: Backend,
+    Expr: Expression<SqlType = Bool> + QueryFragment<DB>,
+{
+    fn to_sql(&self, out: &mut DB::QueryBuilder) -> BuildQueryResult {
+        out.push_sql(" HAVING ");
+        self.0.to_sql(out)
+    }
+
+    fn walk_ast(&self, pass: &mut AstPass<DB>) -> QueryResult<()> {
+        self.0.walk_ast(pass)
+    }
+}
+
+impl_query_id!(HavingClause<Expr>);
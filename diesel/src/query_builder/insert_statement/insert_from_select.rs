@@ -0,0 +1,61 @@
+use backend::Backend;
+use expression::Expression;
+use query_builder::{Query, QueryBuilder, QueryFragment, BuildQueryResult, AstPass};
+use query_source::Table;
+use super::ColumnList;
+use result::QueryResult;
+
+/// An `INSERT INTO target (columns) SELECT ...` statement.
+///
+/// The trailing `SELECT` is rendered by delegating to the `QueryFragment`
+/// impl of the underlying select statement, so every query form usable on its
+/// own is usable as an insert source.
+#[derive(Debug, Clone, Copy)]
+#[must_use="Queries are only executed when calling `execute` or similar."]
+pub struct InsertFromSelect<T, Cols, Select> {
+    target: T,
+    columns: Cols,
+    select: Select,
+}
+
+impl<T, Cols, Select> InsertFromSelect<T, Cols, Select> {
+    pub(crate) fn new(target: T, columns: Cols, select: Select) -> Self {
+        InsertFromSelect {
+            target: target,
+            columns: columns,
+            select: select,
+        }
+    }
+}
+
+impl<T, Cols, Select, DB> QueryFragment<DB> for InsertFromSelect<T, Cols, Select> where
+    DB: Backend,
+    T: Table,
+    T::FromClause: QueryFragment<DB>,
+    // A single column or a tuple of columns both implement `Expression` (for the
+    // type check) and `ColumnList` (for rendering bare identifiers), so the
+    // multi-column `INSERT INTO t (a, b) SELECT ...` form type-checks too.
+    Cols: Expression + ColumnList<DB>,
+    // The columns being inserted must accept exactly the type produced by the
+    // select clause of the source query.
+    Select: Query<SqlType = Cols::SqlType> + QueryFragment<DB>,
+{
+    fn to_sql(&self, out: &mut DB::QueryBuilder) -> BuildQueryResult {
+        out.push_sql("INSERT INTO ");
+        try!(self.target.from_clause().to_sql(out));
+        out.push_sql(" (");
+        // `INSERT` column lists must be bare, unqualified names, so they are
+        // rendered through `ColumnList` rather than the columns' fully-qualified
+        // `QueryFragment` impls.
+        try!(self.columns.append_to_column_list(out));
+        out.push_sql(") ");
+        self.select.to_sql(out)
+    }
+
+    fn walk_ast(&self, pass: &mut AstPass<DB>) -> QueryResult<()> {
+        self.select.walk_ast(pass)?;
+        Ok(())
+    }
+}
+
+impl_query_id!(InsertFromSelect<T, Cols, Select>);
@@ -0,0 +1,57 @@
+//! Construction of `INSERT` statements.
+//!
+//! The public entry point is [`insert_into`](fn.insert_into.html), which
+//! returns an [`IncompleteInsertStatement`](struct.IncompleteInsertStatement.html)
+//! whose value source is supplied by one of its builder methods.
+mod insert_from_select;
+
+pub use self::insert_from_select::InsertFromSelect;
+
+use backend::Backend;
+use query_builder::{QueryBuilder, BuildQueryResult};
+use query_source::Table;
+
+/// A list of columns rendered as a bare, unqualified identifier list.
+///
+/// Unlike a column's `QueryFragment` impl, which emits a fully-qualified
+/// `"table"."column"`, this pushes just the column name as required by an
+/// `INSERT` column list. It is implemented for a single column and for tuples
+/// of columns.
+pub trait ColumnList<DB: Backend> {
+    /// Appends the comma-separated, unqualified column names to `out`.
+    fn append_to_column_list(&self, out: &mut DB::QueryBuilder) -> BuildQueryResult;
+}
+
+/// Creates an `INSERT` statement targeting `target`.
+///
+/// The returned builder has no value source yet; call one of its methods
+/// (such as [`from_select`](struct.IncompleteInsertStatement.html#method.from_select))
+/// to describe the rows being inserted.
+pub fn insert_into<T: Table>(target: T) -> IncompleteInsertStatement<T> {
+    IncompleteInsertStatement::new(target)
+}
+
+/// An `INSERT` statement that does not yet have a value source.
+#[derive(Debug, Clone, Copy)]
+#[must_use="Queries are only executed when calling `execute` or similar."]
+pub struct IncompleteInsertStatement<T> {
+    target: T,
+}
+
+impl<T: Table> IncompleteInsertStatement<T> {
+    fn new(target: T) -> Self {
+        IncompleteInsertStatement { target: target }
+    }
+
+    /// Populates the target table directly from a query, producing
+    /// `INSERT INTO target (columns) SELECT ...`.
+    ///
+    /// The select statement's `SqlType` must structurally match the SQL types
+    /// of `columns`, so the database never needs to round-trip the rows through
+    /// the client.
+    pub fn from_select<Cols, Select>(self, columns: Cols, select: Select)
+        -> InsertFromSelect<T, Cols, Select>
+    {
+        InsertFromSelect::new(self.target, columns, select)
+    }
+}
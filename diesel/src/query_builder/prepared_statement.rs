@@ -0,0 +1,178 @@
+//! Reusable server-side prepared statements with explicit bind placeholders.
+//!
+//! [`PrepareDsl::prepare`](trait.PrepareDsl.html#method.prepare) walks a
+//! query's AST to enumerate its bind slots and returns a
+//! [`PreparedStatement`](struct.PreparedStatement.html) that can be executed
+//! repeatedly with fresh values without rebuilding the SQL. On Postgres this
+//! renders a `PREPARE <name> AS <sql>` / `EXECUTE <name>(...)` pair; other
+//! backends fall back to their cached-statement reuse.
+
+use std::fmt::Debug;
+
+use backend::Backend;
+use query_builder::{Query, QueryBuilder, QueryFragment, BuildQueryResult, AstPass};
+use result::QueryResult;
+
+/// The bind slots collected from a prepared statement.
+///
+/// Only the number of binds discovered while walking the source query is
+/// recorded; the positional placeholders (`$1`, `$2`, ...) themselves are those
+/// rendered in the source query's SQL. The slots are read-only: Postgres `EXECUTE`
+/// accepts only positional value expressions, so there is no meaningful named
+/// form to expose.
+#[derive(Debug, Clone, Default)]
+pub struct Bindings {
+    count: usize,
+}
+
+impl Bindings {
+    /// The number of bind slots collected from the statement.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Whether the statement has no bind slots.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+/// A handle to a named, server-side prepared statement.
+#[derive(Debug, Clone)]
+#[must_use="Prepared statements are only executed when calling `execute` or similar."]
+pub struct PreparedStatement<Source> {
+    name: String,
+    source: Source,
+    bindings: Bindings,
+}
+
+impl<Source> PreparedStatement<Source> {
+    fn new(name: String, source: Source, bindings: Bindings) -> Self {
+        PreparedStatement { name: name, source: source, bindings: bindings }
+    }
+
+    /// The name the statement was prepared under.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The bind slots discovered while preparing the statement.
+    pub fn bindings(&self) -> &Bindings {
+        &self.bindings
+    }
+
+    /// Builds the `EXECUTE` form supplying fresh bind values.
+    ///
+    /// `binds` renders the comma-separated value list; because the placeholders
+    /// come from the values themselves, the emitted arity always matches the
+    /// values provided.
+    pub fn execute_with<Binds>(&self, binds: Binds) -> Execute<Binds> {
+        Execute { name: self.name.clone(), binds: binds }
+    }
+}
+
+// `PREPARE ... AS ...` returns no rows, so `PreparedStatement` is deliberately
+// not a `Query`; it is executed for its effect and then reused via `Execute`.
+impl_query_id!(PreparedStatement<Source>);
+
+#[cfg(feature = "postgres")]
+impl<Source> QueryFragment<::pg::Pg> for PreparedStatement<Source> where
+    Source: QueryFragment<::pg::Pg>,
+{
+    fn to_sql(&self, out: &mut <::pg::Pg as Backend>::QueryBuilder) -> BuildQueryResult {
+        out.push_sql("PREPARE ");
+        out.push_sql(&self.name);
+        out.push_sql(" AS ");
+        self.source.to_sql(out)
+    }
+
+    fn walk_ast(&self, pass: &mut AstPass<::pg::Pg>) -> QueryResult<()> {
+        self.source.walk_ast(pass)
+    }
+}
+
+// Backends without `PREPARE <name> AS` syntax reuse diesel's per-execution
+// cached statements instead, so the handle simply renders the source query.
+#[cfg(feature = "sqlite")]
+impl<Source> QueryFragment<::sqlite::Sqlite> for PreparedStatement<Source> where
+    Source: QueryFragment<::sqlite::Sqlite>,
+{
+    fn to_sql(&self, out: &mut <::sqlite::Sqlite as Backend>::QueryBuilder) -> BuildQueryResult {
+        self.source.to_sql(out)
+    }
+
+    fn walk_ast(&self, pass: &mut AstPass<::sqlite::Sqlite>) -> QueryResult<()> {
+        self.source.walk_ast(pass)
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<Source> QueryFragment<::mysql::Mysql> for PreparedStatement<Source> where
+    Source: QueryFragment<::mysql::Mysql>,
+{
+    fn to_sql(&self, out: &mut <::mysql::Mysql as Backend>::QueryBuilder) -> BuildQueryResult {
+        self.source.to_sql(out)
+    }
+
+    fn walk_ast(&self, pass: &mut AstPass<::mysql::Mysql>) -> QueryResult<()> {
+        self.source.walk_ast(pass)
+    }
+}
+
+/// The `EXECUTE <name>(...)` form of a prepared statement, binding fresh values
+/// to the statement's positional placeholders.
+#[derive(Debug, Clone)]
+pub struct Execute<Binds> {
+    name: String,
+    binds: Binds,
+}
+
+impl_query_id!(Execute<Binds>);
+
+#[cfg(feature = "postgres")]
+impl<Binds> QueryFragment<::pg::Pg> for Execute<Binds> where
+    Binds: QueryFragment<::pg::Pg>,
+{
+    fn to_sql(&self, out: &mut <::pg::Pg as Backend>::QueryBuilder) -> BuildQueryResult {
+        out.push_sql("EXECUTE ");
+        out.push_sql(&self.name);
+        out.push_sql("(");
+        try!(self.binds.to_sql(out));
+        out.push_sql(")");
+        Ok(())
+    }
+
+    fn walk_ast(&self, pass: &mut AstPass<::pg::Pg>) -> QueryResult<()> {
+        self.binds.walk_ast(pass)
+    }
+}
+
+/// Turns a query into a reusable named prepared statement.
+pub trait PrepareDsl: Query + Sized {
+    /// Prepares `self` under `name`.
+    ///
+    /// The statement's AST is walked through the existing `walk_ast`/`AstPass`
+    /// machinery — using a `debug_binds` collecting pass — to enumerate its
+    /// bind slots. The closure is then handed the read-only collected
+    /// [`Bindings`](struct.Bindings.html) for inspection (e.g. asserting the
+    /// expected bind count) before the handle is returned.
+    fn prepare<DB, F>(self, name: &str, f: F) -> PreparedStatement<Self> where
+        DB: Backend,
+        Self: QueryFragment<DB>,
+        F: FnOnce(&Bindings),
+    {
+        let mut collected: Vec<Box<Debug>> = Vec::new();
+        {
+            let mut pass = AstPass::debug_binds(&mut collected);
+            // Errors only surface once the statement is executed against a
+            // connection, so a failed walk here is ignored and only the binds
+            // collected before the failure are counted.
+            let _ = self.walk_ast(&mut pass);
+        }
+        let bindings = Bindings { count: collected.len() };
+        f(&bindings);
+        PreparedStatement::new(name.into(), self, bindings)
+    }
+}
+
+impl<T: Query> PrepareDsl for T {}
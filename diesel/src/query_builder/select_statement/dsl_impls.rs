@@ -0,0 +1,40 @@
+use super::SelectStatement;
+use super::super::group_by_clause::GroupByClause;
+use super::super::having_clause::HavingClause;
+use expression::Expression;
+use types::Bool;
+
+impl<F, S, D, W, O, L, Of, GroupByExpr, H>
+    SelectStatement<F, S, D, W, O, L, Of, GroupByClause<GroupByExpr>, H>
+{
+    /// Adds a `HAVING` clause, filtering the grouped rows produced by the
+    /// preceding `GROUP BY`. Only available once a group by clause is present,
+    /// so a `HAVING` without `GROUP BY` will not type-check:
+    ///
+    /// ```compile_fail
+    /// # #[macro_use] extern crate diesel;
+    /// # use diesel::*;
+    /// # use diesel::dsl::count;
+    /// # table! { users (id) { id -> Integer, name -> Text, } }
+    /// # fn main() {
+    /// // no `.group_by(...)` first, so `.having(...)` does not exist here
+    /// users::table.having(count(users::id).gt(1));
+    /// # }
+    /// ```
+    pub fn having<Predicate>(self, predicate: Predicate)
+        -> SelectStatement<F, S, D, W, O, L, Of, GroupByClause<GroupByExpr>, HavingClause<Predicate>> where
+            Predicate: Expression<SqlType = Bool>,
+    {
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            self.order,
+            self.limit,
+            self.offset,
+            self.group_by,
+            HavingClause(predicate),
+        )
+    }
+}
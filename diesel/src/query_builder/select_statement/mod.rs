@@ -8,6 +8,7 @@
 //! L: Limit Clause
 //! Of: Offset Clause
 //! G: Group By Clause
+//! H: Having Clause
 mod dsl_impls;
 mod boxed;
 
@@ -19,6 +20,7 @@ use query_source::*;
 use result::QueryResult;
 use super::distinct_clause::NoDistinctClause;
 use super::group_by_clause::NoGroupByClause;
+use super::having_clause::NoHavingClause;
 use super::limit_clause::NoLimitClause;
 use super::offset_clause::NoOffsetClause;
 use super::order_clause::NoOrderClause;
@@ -38,6 +40,7 @@ pub struct SelectStatement<
     Limit = NoLimitClause,
     Offset = NoOffsetClause,
     GroupBy = NoGroupByClause,
+    Having = NoHavingClause,
 > {
     select: Select,
     from: From,
@@ -47,9 +50,10 @@ pub struct SelectStatement<
     limit: Limit,
     offset: Offset,
     group_by: GroupBy,
+    having: Having,
 }
 
-impl<F, S, D, W, O, L, Of, G> SelectStatement<F, S, D, W, O, L, Of, G> {
+impl<F, S, D, W, O, L, Of, G, H> SelectStatement<F, S, D, W, O, L, Of, G, H> {
     #[cfg_attr(feature = "clippy", allow(too_many_arguments))]
     pub fn new(
         select: S,
@@ -60,6 +64,7 @@ impl<F, S, D, W, O, L, Of, G> SelectStatement<F, S, D, W, O, L, Of, G> {
         limit: L,
         offset: Of,
         group_by: G,
+        having: H,
     ) -> Self {
         SelectStatement {
             select: select,
@@ -70,6 +75,7 @@ impl<F, S, D, W, O, L, Of, G> SelectStatement<F, S, D, W, O, L, Of, G> {
             limit: limit,
             offset: offset,
             group_by: group_by,
+            having: having,
         }
     }
 }
@@ -85,35 +91,36 @@ impl<F> SelectStatement<F> {
             NoLimitClause,
             NoOffsetClause,
             NoGroupByClause,
+            NoHavingClause,
         )
     }
 }
 
-impl<F, S, D, W, O, L, Of, G> Query
-    for SelectStatement<F, S, D, W, O, L, Of, G> where
+impl<F, S, D, W, O, L, Of, G, H> Query
+    for SelectStatement<F, S, D, W, O, L, Of, G, H> where
         S: SelectClauseExpression<F>,
 {
     type SqlType = S::SelectClauseSqlType;
 }
 
 #[cfg(feature = "postgres")]
-impl<F, S, D, W, O, L, Of, G> Expression
-    for SelectStatement<F, S, D, W, O, L, Of, G> where
+impl<F, S, D, W, O, L, Of, G, H> Expression
+    for SelectStatement<F, S, D, W, O, L, Of, G, H> where
         S: SelectClauseExpression<F>,
 {
     type SqlType = ::types::Array<S::SelectClauseSqlType>;
 }
 
 #[cfg(not(feature = "postgres"))]
-impl<F, S, D, W, O, L, Of, G> Expression
-    for SelectStatement<F, S, D, W, O, L, Of, G> where
+impl<F, S, D, W, O, L, Of, G, H> Expression
+    for SelectStatement<F, S, D, W, O, L, Of, G, H> where
         S: SelectClauseExpression<F>,
 {
     type SqlType = S::SelectClauseSqlType;
 }
 
-impl<F, S, D, W, O, L, Of, G, DB> QueryFragment<DB>
-    for SelectStatement<F, S, D, W, O, L, Of, G> where
+impl<F, S, D, W, O, L, Of, G, H, DB> QueryFragment<DB>
+    for SelectStatement<F, S, D, W, O, L, Of, G, H> where
         DB: Backend,
         S: SelectClauseQueryFragment<F, DB>,
         F: QuerySource,
@@ -124,6 +131,7 @@ impl<F, S, D, W, O, L, Of, G, DB> QueryFragment<DB>
         L: QueryFragment<DB>,
         Of: QueryFragment<DB>,
         G: QueryFragment<DB>,
+        H: QueryFragment<DB>,
 {
     fn to_sql(&self, out: &mut DB::QueryBuilder) -> BuildQueryResult {
         out.push_sql("SELECT ");
@@ -133,6 +141,7 @@ impl<F, S, D, W, O, L, Of, G, DB> QueryFragment<DB>
         try!(self.from.from_clause().to_sql(out));
         try!(self.where_clause.to_sql(out));
         try!(self.group_by.to_sql(out));
+        try!(self.having.to_sql(out));
         try!(self.order.to_sql(out));
         try!(self.limit.to_sql(out));
         try!(self.offset.to_sql(out));
@@ -145,6 +154,7 @@ impl<F, S, D, W, O, L, Of, G, DB> QueryFragment<DB>
         self.from.from_clause().walk_ast(pass)?;
         self.where_clause.walk_ast(pass)?;
         self.group_by.walk_ast(pass)?;
+        self.having.walk_ast(pass)?;
         self.order.walk_ast(pass)?;
         self.limit.walk_ast(pass)?;
         self.offset.walk_ast(pass)?;
@@ -152,8 +162,8 @@ impl<F, S, D, W, O, L, Of, G, DB> QueryFragment<DB>
     }
 }
 
-impl<S, D, W, O, L, Of, G, DB> QueryFragment<DB>
-    for SelectStatement<(), S, D, W, O, L, Of, G> where
+impl<S, D, W, O, L, Of, G, H, DB> QueryFragment<DB>
+    for SelectStatement<(), S, D, W, O, L, Of, G, H> where
         DB: Backend,
         S: SelectClauseQueryFragment<(), DB>,
         D: QueryFragment<DB>,
@@ -162,6 +172,7 @@ impl<S, D, W, O, L, Of, G, DB> QueryFragment<DB>
         L: QueryFragment<DB>,
         Of: QueryFragment<DB>,
         G: QueryFragment<DB>,
+        H: QueryFragment<DB>,
 {
     fn to_sql(&self, out: &mut DB::QueryBuilder) -> BuildQueryResult {
         out.push_sql("SELECT ");
@@ -169,6 +180,7 @@ impl<S, D, W, O, L, Of, G, DB> QueryFragment<DB>
         try!(self.select.to_sql(&(), out));
         try!(self.where_clause.to_sql(out));
         try!(self.group_by.to_sql(out));
+        try!(self.having.to_sql(out));
         try!(self.order.to_sql(out));
         try!(self.limit.to_sql(out));
         try!(self.offset.to_sql(out));
@@ -180,6 +192,7 @@ impl<S, D, W, O, L, Of, G, DB> QueryFragment<DB>
         self.select.walk_ast(&(), pass)?;
         self.where_clause.walk_ast(pass)?;
         self.group_by.walk_ast(pass)?;
+        self.having.walk_ast(pass)?;
         self.order.walk_ast(pass)?;
         self.limit.walk_ast(pass)?;
         self.offset.walk_ast(pass)?;
@@ -187,22 +200,22 @@ impl<S, D, W, O, L, Of, G, DB> QueryFragment<DB>
     }
 }
 
-impl_query_id!(SelectStatement<F, S, D, W, O, L, Of, G>);
+impl_query_id!(SelectStatement<F, S, D, W, O, L, Of, G, H>);
 
-impl<F, S, D, W, O, L, Of, G, QS> SelectableExpression<QS>
-    for SelectStatement<F, S, D, W, O, L, Of, G> where
-        SelectStatement<F, S, D, W, O, L, Of, G>: AppearsOnTable<QS>,
+impl<F, S, D, W, O, L, Of, G, H, QS> SelectableExpression<QS>
+    for SelectStatement<F, S, D, W, O, L, Of, G, H> where
+        SelectStatement<F, S, D, W, O, L, Of, G, H>: AppearsOnTable<QS>,
 {
 }
 
-impl<S, F, D, W, O, L, Of, G, QS> AppearsOnTable<QS>
-    for SelectStatement<S, F, D, W, O, L, Of, G> where
-        SelectStatement<S, F, D, W, O, L, Of, G>: Expression,
+impl<S, F, D, W, O, L, Of, G, H, QS> AppearsOnTable<QS>
+    for SelectStatement<S, F, D, W, O, L, Of, G, H> where
+        SelectStatement<S, F, D, W, O, L, Of, G, H>: Expression,
 {
 }
 
-impl<F, S, D, W, O, L, Of, G> NonAggregate
-    for SelectStatement<F, S, D, W, O, L, Of, G> where
-        SelectStatement<F, S, D, W, O, L, Of, G>: Expression,
+impl<F, S, D, W, O, L, Of, G, H> NonAggregate
+    for SelectStatement<F, S, D, W, O, L, Of, G, H> where
+        SelectStatement<F, S, D, W, O, L, Of, G, H>: Expression,
 {
 }
@@ -0,0 +1,142 @@
+//! Rendering tests for the query-builder additions: the `HAVING` clause,
+//! `INSERT ... SELECT`, the per-backend full-text `MATCH`/`@@` operators, and
+//! the `UNION`/`INTERSECT`/`EXCEPT` combinators (with trailing
+//! `ORDER BY`/`LIMIT`). The group-by-gated `.having` guard is checked by the
+//! `compile_fail` doctest on `SelectStatement::having`, not here.
+
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::dsl::count;
+use diesel::expression::full_text_search::FullTextMatchDsl;
+use diesel::pg::Pg;
+use diesel::query_builder::combination_clause::CombineDsl;
+use diesel::query_builder::insert_statement::insert_into;
+use diesel::sqlite::Sqlite;
+
+table! {
+    users (id) {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+table! {
+    articles (id) {
+        id -> Integer,
+        body -> Text,
+    }
+}
+
+// `debug_query` output ends with ` -- binds: [...]`; keep only the SQL text so
+// that `ends_with` assertions look at the end of the statement itself.
+fn sqlite_sql<T: QueryFragment<Sqlite>>(query: T) -> String {
+    let out = debug_query::<Sqlite, _>(&query).to_string();
+    out.split(" -- binds:").next().unwrap_or("").to_string()
+}
+
+fn pg_sql<T: QueryFragment<Pg>>(query: T) -> String {
+    let out = debug_query::<Pg, _>(&query).to_string();
+    out.split(" -- binds:").next().unwrap_or("").to_string()
+}
+
+#[test]
+fn having_is_rendered_after_group_by() {
+    let query = users::table
+        .group_by(users::name)
+        .having(count(users::id).gt(1));
+    let sql = sqlite_sql(query);
+
+    assert!(
+        sql.contains(r#"GROUP BY "users"."name" HAVING COUNT("users"."id") > ?"#),
+        "unexpected SQL: {}",
+        sql
+    );
+}
+
+#[test]
+fn insert_from_select_uses_bare_column_names() {
+    let query = insert_into(users::table)
+        .from_select(users::name, users::table.select(users::name));
+    let sql = sqlite_sql(query);
+
+    // The column list must be unqualified, not `("users"."name")`.
+    assert!(
+        sql.starts_with(r#"INSERT INTO "users" ("name") SELECT "users"."name" FROM "users""#),
+        "unexpected SQL: {}",
+        sql
+    );
+}
+
+#[test]
+fn full_text_match_uses_match_on_sqlite() {
+    let query = articles::table.filter(articles::body.full_text_match("rust"));
+    let sql = sqlite_sql(query);
+
+    assert!(
+        sql.contains(r#"WHERE "articles"."body" MATCH ?"#),
+        "unexpected SQL: {}",
+        sql
+    );
+}
+
+#[test]
+fn full_text_match_uses_tsquery_on_postgres() {
+    let query = articles::table.filter(articles::body.full_text_match("rust"));
+    let sql = pg_sql(query);
+
+    assert!(
+        sql.contains(r#"WHERE "articles"."body" @@ to_tsquery($1)"#),
+        "unexpected SQL: {}",
+        sql
+    );
+}
+
+#[test]
+fn combinators_wrap_each_operand_in_parentheses() {
+    let lhs = users::table.select(users::id);
+    let rhs = users::table.select(users::id);
+    let sql = sqlite_sql(lhs.union(rhs));
+
+    assert!(sql.starts_with("(SELECT"), "unexpected SQL: {}", sql);
+    assert!(sql.contains(") UNION ("), "unexpected SQL: {}", sql);
+    assert!(sql.ends_with(")"), "unexpected SQL: {}", sql);
+}
+
+#[test]
+fn combinator_keywords() {
+    let pairs = [
+        (
+            sqlite_sql(users::table.select(users::id).union_all(users::table.select(users::id))),
+            ") UNION ALL (",
+        ),
+        (
+            sqlite_sql(users::table.select(users::id).intersect(users::table.select(users::id))),
+            ") INTERSECT (",
+        ),
+        (
+            sqlite_sql(users::table.select(users::id).except(users::table.select(users::id))),
+            ") EXCEPT (",
+        ),
+    ];
+    for &(ref sql, keyword) in &pairs {
+        assert!(sql.contains(keyword), "missing {} in {}", keyword, sql);
+    }
+}
+
+#[test]
+fn order_and_limit_wrap_the_whole_compound() {
+    let lhs = users::table.select(users::id);
+    let rhs = users::table.select(users::id);
+    let query = lhs.union(rhs).order(users::id).limit(5);
+    let sql = sqlite_sql(query);
+
+    // The trailing clauses must appear after the closing parenthesis of the
+    // right-hand operand, not inside either SELECT.
+    assert!(
+        sql.ends_with(r#") ORDER BY "users"."id" LIMIT ?"#),
+        "unexpected SQL: {}",
+        sql
+    );
+}